@@ -7,17 +7,32 @@
 //! responders; when they do, the responder finalizes the response by writing
 //! out additional headers and, importantly, the body of the response.
 
+use std::cmp;
+use std::ops::RangeInclusive;
 use std::hash::{Hash, Hasher};
 use std::collections::hash_map::DefaultHasher;
 
 use response::{Responder, Response};
-use http::hyper::header;
-use http::Status;
+use http::hyper::header::{self, ContentRangeSpec, EntityTag, RangeUnit};
+use http::{Header, Status};
+
+/// How the `ETag` header of a [`Created`] response, if any, is computed.
+enum ETagPolicy {
+    /// No `ETag` header is sent.
+    None,
+    /// The body responder is hashed with `DefaultHasher` into an `ETag`,
+    /// strong or weak as given.
+    Hash { weak: bool },
+    /// The given `ETag` is sent as-is, with no hashing.
+    Fixed(EntityTag),
+}
 
 /// Sets the status of the response to 201 (Created).
 ///
-/// The `String` field is set as the value of the `Location` header in the
-/// response. The optional `Responder` field is used to finalize the response.
+/// Sets the `Location` header to the value given to [`Created::new`]. The
+/// optional responder, set via [`Created::body`] or one of its tagging
+/// variants, finalizes the response; if none is given, the response body
+/// will be empty.
 ///
 /// # Example
 ///
@@ -26,45 +41,366 @@ use http::Status;
 ///
 /// let url = "http://myservice.com/resource.json".to_string();
 /// let content = "{ 'resource': 'Hello, world!' }";
-/// let response = status::Created(url, Some(content));
+/// let response = status::Created::new(url).body(content);
 /// ```
-pub struct Created<R>(pub String, pub Option<R>);
+pub struct Created<R> {
+    location: String,
+    responder: Option<R>,
+    etag: ETagPolicy,
+}
 
-/// Sets the status code of the response to 201 Created. Sets the `Location`
-/// header to the `String` parameter in the constructor.
-///
-/// The optional responder finalizes the response if it exists. The wrapped
-/// responder should write the body of the response so that it contains
-/// information about the created resource. If no responder is provided, the
-/// response body will be empty.
+impl<R> Created<R> {
+    /// Starts a `201 Created` response pointing the `Location` header at
+    /// `location`, with no body and no `ETag`.
+    pub fn new<L: Into<String>>(location: L) -> Created<R> {
+        Created {
+            location: location.into(),
+            responder: None,
+            etag: ETagPolicy::None,
+        }
+    }
+
+    /// Sets `responder` as the body of the response. No `ETag` is sent; use
+    /// [`Created::tagged_body`] or [`Created::tagged_body_with`] if one is
+    /// wanted.
+    pub fn body(mut self, responder: R) -> Self {
+        self.responder = Some(responder);
+        self
+    }
+
+    /// Sets `responder` as the body of the response, and computes a strong
+    /// `ETag` by hashing it with `DefaultHasher`.
+    pub fn tagged_body(mut self, responder: R) -> Self where R: Hash {
+        self.responder = Some(responder);
+        self.etag = ETagPolicy::Hash { weak: false };
+        self
+    }
+
+    /// Like [`Created::tagged_body`], but emits a *weak* `ETag`
+    /// (`W/"..."`), indicating the tag covers a semantically—rather than
+    /// byte-for-byte—equivalent representation.
+    pub fn weak_tagged_body(mut self, responder: R) -> Self where R: Hash {
+        self.responder = Some(responder);
+        self.etag = ETagPolicy::Hash { weak: true };
+        self
+    }
+
+    /// Sets `responder` as the body of the response, tagged with the given
+    /// `tag` rather than a hash of the body. Useful for content-addressed
+    /// resources that already have a natural, stable identifier, since a
+    /// `DefaultHasher` value isn't guaranteed to be stable across Rust
+    /// releases.
+    pub fn tagged_body_with(mut self, responder: R, tag: EntityTag) -> Self {
+        self.responder = Some(responder);
+        self.etag = ETagPolicy::Fixed(tag);
+        self
+    }
+
+    /// Like [`Created::tagged_body`], but hashes `responder` with `H`
+    /// instead of `DefaultHasher`. Lets a caller pin the hash algorithm so
+    /// the resulting `ETag` can't shift out from under them across Rust
+    /// releases.
+    pub fn tagged_body_with_hasher<H>(mut self, responder: R) -> Self
+        where R: Hash, H: Hasher + Default
+    {
+        let tag = EntityTag::strong(hash_with::<R, H>(&responder));
+        self.responder = Some(responder);
+        self.etag = ETagPolicy::Fixed(tag);
+        self
+    }
+
+    /// Like [`Created::tagged_body_with_hasher`], but emits a *weak* `ETag`
+    /// (`W/"..."`).
+    pub fn weak_tagged_body_with_hasher<H>(mut self, responder: R) -> Self
+        where R: Hash, H: Hasher + Default
+    {
+        let tag = EntityTag::weak(hash_with::<R, H>(&responder));
+        self.responder = Some(responder);
+        self.etag = ETagPolicy::Fixed(tag);
+        self
+    }
+}
+
+/// Hashes `value` with a freshly-`Default`-constructed `H`, returning the
+/// result formatted as a string suitable for use as an `ETag`'s tag.
+fn hash_with<T: Hash, H: Hasher + Default>(value: &T) -> String {
+    let mut hasher = H::default();
+    value.hash(&mut hasher);
+    hasher.finish().to_string()
+}
+
+/// Sets the status code of the response to 201 Created and the `Location`
+/// header to the location given to [`Created::new`]. The optional responder
+/// finalizes the response, after which the `ETag` header is set according to
+/// the configured [`ETagPolicy`].
 impl<'r, R: Responder<'r>> Responder<'r> for Created<R> {
     default fn respond(self) -> Result<Response<'r>, Status> {
         let mut build = Response::build();
-        if let Some(responder) = self.1 {
+        if let Some(responder) = self.responder {
             build.merge(responder.respond()?);
         }
 
-        build.status(Status::Created).header(header::Location(self.0)).ok()
+        if let ETagPolicy::Fixed(tag) = self.etag {
+            build.header(header::ETag(tag));
+        }
+
+        build.status(Status::Created)
+            .header(header::Location(self.location))
+            .ok()
     }
 }
 
-/// In addition to setting the status code, `Location` header, and finalizing
-/// the response with the `Responder`, the `ETag` header is set conditionally if
-/// a `Responder` is provided that implements `Hash`. The `ETag` header is set
-/// to a hash value of the responder.
+/// In addition to the behavior above, this specialization is able to
+/// actually hash the responder when the `ETagPolicy` calls for it, since
+/// doing so requires `R: Hash`.
 impl<'r, R: Responder<'r> + Hash> Responder<'r> for Created<R> {
     fn respond(self) -> Result<Response<'r>, Status> {
-        let mut hasher = DefaultHasher::default();
         let mut build = Response::build();
-        if let Some(responder) = self.1 {
-            responder.hash(&mut hasher);
-            let hash = hasher.finish().to_string();
 
+        let tag = match self.etag {
+            ETagPolicy::Hash { weak } => self.responder.as_ref().map(|r| {
+                let hash = hash_with::<R, DefaultHasher>(r);
+                if weak {
+                    EntityTag::weak(hash)
+                } else {
+                    EntityTag::strong(hash)
+                }
+            }),
+            ETagPolicy::Fixed(tag) => Some(tag),
+            ETagPolicy::None => None,
+        };
+
+        if let Some(responder) = self.responder {
             build.merge(responder.respond()?);
-            build.header(header::ETag(header::EntityTag::strong(hash)));
         }
 
-        build.status(Status::Created).header(header::Location(self.0)).ok()
+        if let Some(tag) = tag {
+            build.header(header::ETag(tag));
+        }
+
+        build.status(Status::Created)
+            .header(header::Location(self.location))
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod created_tests {
+    use super::{Created, Responder, Response, Status};
+    use http::hyper::header::{self, EntityTag};
+
+    // Does *not* implement `Hash`, so `Created<PlainWithEtag>` only has the
+    // default (non-specialized) `Responder` impl to pick from.
+    struct PlainWithEtag(&'static str);
+
+    impl<'r> Responder<'r> for PlainWithEtag {
+        fn respond(self) -> Result<Response<'r>, Status> {
+            let clobber = EntityTag::strong("clobbered".into());
+            Response::build().header(header::ETag(clobber)).ok()
+        }
+    }
+
+    // Implements `Hash`, so `Created<HashableWithEtag>` picks the
+    // specialized `Responder` impl that hashes the body into an `ETag`.
+    #[derive(Hash)]
+    struct HashableWithEtag(&'static str);
+
+    impl<'r> Responder<'r> for HashableWithEtag {
+        fn respond(self) -> Result<Response<'r>, Status> {
+            let clobber = EntityTag::strong("clobbered".into());
+            Response::build().header(header::ETag(clobber)).ok()
+        }
+    }
+
+    #[test]
+    fn fixed_etag_survives_a_responder_that_also_sets_one() {
+        let tag = EntityTag::strong("computed".into());
+        let created = Created::new("/widgets/1")
+            .tagged_body_with(PlainWithEtag("body"), tag);
+
+        let response = created.respond().unwrap();
+        assert_eq!(response.headers().get_one("ETag"), Some("\"computed\""));
+    }
+
+    #[test]
+    fn hashed_etag_survives_a_responder_that_also_sets_one() {
+        let created = Created::new("/widgets/1")
+            .tagged_body(HashableWithEtag("body"));
+
+        let response = created.respond().unwrap();
+        assert_ne!(response.headers().get_one("ETag"), Some("\"clobbered\""));
+    }
+}
+
+/// Turns a `Responder` into a conditional-GET cache, building on the `ETag`
+/// hashing that backs [`Created`].
+///
+/// `Conditional` isn't itself a `Responder`: answering a conditional request
+/// needs the incoming `If-None-Match` and `If-Modified-Since` header values,
+/// and `Responder::respond` has no access to the request. Instead, a
+/// handler extracts those headers itself (for example through a request
+/// guard) and passes them to [`Conditional::respond`].
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::response::status;
+/// use rocket::http::hyper::header::EntityTag;
+///
+/// # let if_none_match: Option<&str> = None;
+/// let etag = EntityTag::strong("33a64df".into());
+/// let conditional = status::Conditional::new("Hello, world!").etag(etag);
+/// let response = conditional.respond(if_none_match, None);
+/// ```
+pub struct Conditional<R> {
+    responder: R,
+    etag: Option<EntityTag>,
+    last_modified: Option<String>,
+}
+
+impl<R> Conditional<R> {
+    /// Wraps `responder` with no `ETag` or `Last-Modified` value yet; add
+    /// one or both with [`Conditional::etag`] and
+    /// [`Conditional::last_modified`].
+    pub fn new(responder: R) -> Conditional<R> {
+        Conditional { responder, etag: None, last_modified: None }
+    }
+
+    /// Sets the `ETag` to compare incoming `If-None-Match` headers against.
+    pub fn etag(mut self, etag: EntityTag) -> Self {
+        self.etag = Some(etag);
+        self
+    }
+
+    /// Sets the `Last-Modified` value, formatted as an HTTP-date, to compare
+    /// incoming `If-Modified-Since` headers against.
+    pub fn last_modified(mut self, last_modified: String) -> Self {
+        self.last_modified = Some(last_modified);
+        self
+    }
+
+    /// Returns `true` if the client's cached copy, as described by
+    /// `if_none_match` and `if_modified_since`, is still fresh.
+    ///
+    /// Per RFC 7232 §3.3, `If-Modified-Since` is only considered when
+    /// `If-None-Match` is absent; a recipient must ignore it otherwise. That
+    /// makes these two checks mutually exclusive: the first `if let`
+    /// already covers every case where `If-None-Match` applies, so falling
+    /// through to the `Last-Modified` check means it doesn't.
+    fn is_fresh(
+        &self,
+        if_none_match: Option<&str>,
+        if_modified_since: Option<&str>
+    ) -> bool {
+        if let Some(if_none_match) = if_none_match {
+            return if_none_match.split(',').any(|part| {
+                let part = part.trim();
+                let part = part.trim_start_matches("W/").trim_matches('"');
+                part == "*"
+                    || self.etag.as_ref().map_or(false, |etag| part == etag.tag())
+            });
+        }
+
+        if let (Some(ref last_modified), Some(if_modified_since))
+            = (&self.last_modified, if_modified_since)
+        {
+            if if_modified_since == last_modified {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Compares the stored `ETag`/`Last-Modified` metadata against the
+    /// incoming `If-None-Match` and `If-Modified-Since` request header
+    /// values (`If-Modified-Since` is only consulted when `If-None-Match` is
+    /// absent). If they indicate the client's cached copy is still fresh,
+    /// returns a bodyless `304 Not Modified` that still carries the `ETag`
+    /// and `Last-Modified` headers, so caches can refresh their metadata.
+    /// Otherwise, finalizes the response with the wrapped responder, setting
+    /// whichever of `ETag` and `Last-Modified` were provided.
+    pub fn respond<'r>(
+        self,
+        if_none_match: Option<&str>,
+        if_modified_since: Option<&str>
+    ) -> Result<Response<'r>, Status>
+        where R: Responder<'r>
+    {
+        let fresh = self.is_fresh(if_none_match, if_modified_since);
+        let mut build = Response::build();
+
+        if !fresh {
+            build.merge(self.responder.respond()?);
+        }
+
+        if let Some(etag) = self.etag {
+            build.header(header::ETag(etag));
+        }
+
+        if let Some(last_modified) = self.last_modified {
+            build.header(Header::new("Last-Modified", last_modified));
+        }
+
+        if fresh {
+            build.status(Status::NotModified);
+        }
+
+        build.ok()
+    }
+}
+
+#[cfg(test)]
+mod conditional_tests {
+    use super::{Conditional, Status};
+    use http::hyper::header::EntityTag;
+
+    #[test]
+    fn matching_if_none_match_is_fresh() {
+        let etag = EntityTag::strong("abc".into());
+        let conditional = Conditional::new("body").etag(etag);
+        assert!(conditional.is_fresh(Some("\"abc\""), None));
+    }
+
+    #[test]
+    fn non_matching_if_none_match_is_not_fresh() {
+        let etag = EntityTag::strong("abc".into());
+        let conditional = Conditional::new("body").etag(etag);
+        assert!(!conditional.is_fresh(Some("\"xyz\""), None));
+    }
+
+    #[test]
+    fn if_none_match_takes_precedence_over_if_modified_since() {
+        let etag = EntityTag::strong("abc".into());
+        let conditional = Conditional::new("body")
+            .etag(etag)
+            .last_modified("Wed, 21 Oct 2015 07:28:00 GMT".into());
+
+        // The ETag doesn't match, so the coincidentally-matching
+        // If-Modified-Since must be ignored, not treated as fresh.
+        let fresh = conditional.is_fresh(
+            Some("\"xyz\""),
+            Some("Wed, 21 Oct 2015 07:28:00 GMT"));
+        assert!(!fresh);
+    }
+
+    #[test]
+    fn matching_if_modified_since_is_fresh_without_if_none_match() {
+        let conditional = Conditional::new("body")
+            .last_modified("Wed, 21 Oct 2015 07:28:00 GMT".into());
+
+        let fresh = conditional.is_fresh(None, Some("Wed, 21 Oct 2015 07:28:00 GMT"));
+        assert!(fresh);
+    }
+
+    #[test]
+    fn not_modified_response_carries_the_etag() {
+        let etag = EntityTag::strong("abc".into());
+        let conditional = Conditional::new("body").etag(etag);
+
+        let response = conditional.respond(Some("\"abc\""), None).unwrap();
+        assert_eq!(response.status(), Status::NotModified);
+        assert_eq!(response.headers().get_one("ETag"), Some("\"abc\""));
     }
 }
 
@@ -107,41 +443,59 @@ impl<'r, R: Responder<'r>> Responder<'r> for Accepted<R> {
 
 /// Sets the status of the response to 204 (No Content).
 ///
+/// The `Vec<Header>` is added to the response, letting a handler attach
+/// headers such as `ETag` or `Cache-Control` to an otherwise bodyless
+/// response, which is common for `PUT` and `DELETE` responses.
+///
 /// # Example
 ///
 /// ```rust
 /// use rocket::response::status;
 ///
-/// let response = status::NoContent;
+/// let response = status::NoContent(vec![]);
 /// ```
-// TODO: This would benefit from Header support.
-pub struct NoContent;
+pub struct NoContent(pub Vec<Header<'static>>);
 
 /// Sets the status code of the response to 204 No Content. The body of the
-/// response will be empty.
+/// response will be empty. The headers in the `Vec` are added to the
+/// response.
 impl<'r> Responder<'r> for NoContent {
     fn respond(self) -> Result<Response<'r>, Status> {
-        Response::build().status(Status::NoContent).ok()
+        let mut build = Response::build();
+        for header in self.0 {
+            build.header(header);
+        }
+
+        build.status(Status::NoContent).ok()
     }
 }
 
 
 /// Sets the status of the response to 205 (Reset Content).
 ///
+/// The `Vec<Header>` is added to the response, letting a handler attach
+/// headers to an otherwise bodyless response.
+///
 /// # Example
 ///
 /// ```rust
 /// use rocket::response::status;
 ///
-/// let response = status::Reset;
+/// let response = status::Reset(vec![]);
 /// ```
-pub struct Reset;
+pub struct Reset(pub Vec<Header<'static>>);
 
 /// Sets the status code of the response to 205 Reset Content. The body of the
-/// response will be empty.
+/// response will be empty. The headers in the `Vec` are added to the
+/// response.
 impl<'r> Responder<'r> for Reset {
     fn respond(self) -> Result<Response<'r>, Status> {
-        Response::build().status(Status::ResetContent).ok()
+        let mut build = Response::build();
+        for header in self.0 {
+            build.header(header);
+        }
+
+        build.status(Status::ResetContent).ok()
     }
 }
 
@@ -167,5 +521,226 @@ impl<'r, R: Responder<'r>> Responder<'r> for Custom<R> {
     }
 }
 
-// The following are unimplemented.
-// 206 Partial Content (variant), 203 Non-Authoritative Information (headers).
+/// Sets the status of the response to 206 (Partial Content).
+///
+/// The wrapped responder should itself write out only the bytes within
+/// `range`; `PartialContent` is responsible for the surrounding metadata.
+/// `range` is the inclusive byte span being returned, which `RangeInclusive`
+/// encodes directly: a caller can slice a body with
+/// `&body[*range.start() as usize..=*range.end() as usize]` without
+/// dropping the last byte. It's rendered as the `Content-Range` header:
+/// `bytes {start}-{end}/{total}`, or `bytes {start}-{end}/*` if `total` is
+/// unknown. `Accept-Ranges: bytes` is always set so clients know the
+/// resource supports range requests.
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::response::status;
+///
+/// // The full resource is 100 bytes; we're returning the last 10 of them.
+/// let body = "0123456789";
+/// let response = status::PartialContent::new(body, 90..=99, Some(100));
+/// ```
+pub struct PartialContent<R> {
+    responder: R,
+    range: RangeInclusive<u64>,
+    total: Option<u64>,
+}
+
+impl<R> PartialContent<R> {
+    /// Constructs a `PartialContent` wrapping `responder`, which is assumed
+    /// to write out exactly the inclusive byte range `range` of a resource
+    /// that is `total` bytes long overall, if known.
+    pub fn new(
+        responder: R,
+        range: RangeInclusive<u64>,
+        total: Option<u64>
+    ) -> PartialContent<R> {
+        PartialContent { responder, range, total }
+    }
+}
+
+/// Sets the status code of the response to 206 Partial Content, and sets the
+/// `Content-Range` and `Accept-Ranges` headers to describe `range`. The
+/// wrapped responder finalizes the rest of the response.
+impl<'r, R: Responder<'r>> Responder<'r> for PartialContent<R> {
+    fn respond(self) -> Result<Response<'r>, Status> {
+        let mut build = Response::build();
+        build.merge(self.responder.respond()?);
+
+        let content_range = ContentRangeSpec::Bytes {
+            range: Some((*self.range.start(), *self.range.end())),
+            instance_length: self.total,
+        };
+
+        build.status(Status::PartialContent)
+            .header(header::ContentRange(content_range))
+            .header(header::AcceptRanges(vec![RangeUnit::Bytes]))
+            .ok()
+    }
+}
+
+/// Sets the status of the response to 416 (Range Not Satisfiable).
+///
+/// Returned by [`parse_range`] when the requested range starts beyond the
+/// end of the resource. Sets `Content-Range: bytes */{total}` so the client
+/// learns the resource's actual length.
+pub struct RangeNotSatisfiable(pub u64);
+
+/// Sets the status code of the response to 416 Range Not Satisfiable. The
+/// body of the response will be empty.
+impl<'r> Responder<'r> for RangeNotSatisfiable {
+    fn respond(self) -> Result<Response<'r>, Status> {
+        let content_range = ContentRangeSpec::Bytes {
+            range: None,
+            instance_length: Some(self.0),
+        };
+
+        Response::build()
+            .status(Status::RangeNotSatisfiable)
+            .header(header::ContentRange(content_range))
+            .ok()
+    }
+}
+
+/// Parses the value of an incoming `Range` request header for a resource
+/// that is `total` bytes long.
+///
+/// Returns `Ok(Some(range))` with the single, inclusive byte range that was
+/// requested. Returns `Ok(None)` if `header` doesn't parse as a `bytes`
+/// range, if it names more than one range, if it is a suffix range such as
+/// `bytes=-500`, or if its end comes before its start; multi-range and
+/// suffix-range requests aren't supported, so the caller should fall back to
+/// a full 200 response in all of these cases. Returns `Err(RangeNotSatisfiable)` if the requested range
+/// starts at or beyond `total`; the caller should return that value as a
+/// 416 response.
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::response::status::parse_range;
+///
+/// assert_eq!(parse_range("bytes=0-499", 1000), Ok(Some(0..=499)));
+/// assert_eq!(parse_range("bytes=500-", 1000), Ok(Some(500..=999)));
+/// assert!(parse_range("bytes=1000-1499", 1000).is_err());
+/// ```
+pub fn parse_range(
+    header: &str,
+    total: u64
+) -> Result<Option<RangeInclusive<u64>>, RangeNotSatisfiable> {
+    if !header.starts_with("bytes=") || header.contains(',') {
+        return Ok(None);
+    }
+
+    let spec = &header["bytes=".len()..];
+    let mut parts = spec.splitn(2, '-');
+    let (start, end) = match (parts.next(), parts.next()) {
+        (Some(start), Some(end)) => (start, end),
+        _ => return Ok(None),
+    };
+
+    let start: u64 = match start.parse() {
+        Ok(start) => start,
+        Err(_) => return Ok(None),
+    };
+
+    if start >= total {
+        return Err(RangeNotSatisfiable(total));
+    }
+
+    let end = match end {
+        "" => total - 1,
+        end => match end.parse() {
+            Ok(end) => cmp::min(end, total - 1),
+            Err(_) => return Ok(None),
+        }
+    };
+
+    if end < start {
+        return Ok(None);
+    }
+
+    Ok(Some(start..=end))
+}
+
+#[cfg(test)]
+mod parse_range_tests {
+    use super::parse_range;
+
+    #[test]
+    fn parses_a_simple_range() {
+        assert_eq!(parse_range("bytes=0-499", 1000), Ok(Some(0..=499)));
+    }
+
+    #[test]
+    fn parses_a_suffix_range() {
+        assert_eq!(parse_range("bytes=500-", 1000), Ok(Some(500..=999)));
+    }
+
+    #[test]
+    fn clamps_an_end_beyond_the_total() {
+        assert_eq!(parse_range("bytes=500-999999", 1000), Ok(Some(500..=999)));
+    }
+
+    #[test]
+    fn rejects_a_start_beyond_the_total() {
+        assert!(parse_range("bytes=1000-1499", 1000).is_err());
+    }
+
+    #[test]
+    fn rejects_an_end_before_the_start() {
+        assert_eq!(parse_range("bytes=500-100", 1000), Ok(None));
+    }
+
+    #[test]
+    fn falls_back_to_full_content_on_multiple_ranges() {
+        assert_eq!(parse_range("bytes=0-50,100-150", 1000), Ok(None));
+    }
+
+    #[test]
+    fn falls_back_to_full_content_on_unparseable_input() {
+        assert_eq!(parse_range("bytes=abc-def", 1000), Ok(None));
+        assert_eq!(parse_range("not-a-range", 1000), Ok(None));
+    }
+
+    #[test]
+    fn falls_back_to_full_content_on_unsupported_suffix_ranges() {
+        // `bytes=-500` ("last 500 bytes") isn't supported yet.
+        assert_eq!(parse_range("bytes=-500", 1000), Ok(None));
+    }
+}
+
+/// Sets the status of the response to 203 (Non-Authoritative Information).
+///
+/// The wrapped `Responder` finalizes the body of the response exactly as
+/// with [`Custom`]. The `Vec<Header>` is then merged in on top, giving a
+/// proxy or other transforming intermediary a way to signal that the
+/// metadata it's returning may differ from the origin's.
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::response::status;
+/// use rocket::http::Header;
+///
+/// let headers = vec![Header::new("X-Proxied-By", "cache.example.com")];
+/// let response = status::NonAuthoritative(headers, "Hello, world!");
+/// ```
+pub struct NonAuthoritative<R>(pub Vec<Header<'static>>, pub R);
+
+/// Sets the status code of the response to 203 Non-Authoritative
+/// Information. The wrapped responder finalizes the response, after which
+/// the headers in the `Vec` are merged in.
+impl<'r, R: Responder<'r>> Responder<'r> for NonAuthoritative<R> {
+    fn respond(self) -> Result<Response<'r>, Status> {
+        let mut build = Response::build();
+        build.merge(self.1.respond()?);
+
+        for header in self.0 {
+            build.header(header);
+        }
+
+        build.status(Status::NonAuthoritative).ok()
+    }
+}